@@ -0,0 +1,13 @@
+/// Actions a component can ask the app to apply centrally, rather than
+/// mutating shared state directly from inside `handle_event`.
+#[derive(Debug, Clone)]
+pub enum AppAction {
+    Quit,
+    Search(String),
+    Filter(String),
+    ResetFilter,
+    Play,
+    FocusSearch,
+    FocusFilter,
+    FocusNormal,
+}
@@ -0,0 +1,23 @@
+use crate::action::AppAction;
+use crossterm::event::Event;
+use ratatui::prelude::*;
+use std::io;
+
+/// Common interface implemented by each piece of the UI.
+///
+/// `App::run` feeds each terminal event to the focused component(s) via
+/// `handle_event`, collects whatever `AppAction` comes back, and applies it
+/// centrally rather than leaving every component free to mutate the rest of
+/// the app's state directly.
+pub trait Component {
+    /// One-time setup; most components don't need this.
+    fn init(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Translates a raw terminal event into an action this component wants
+    /// applied, if any.
+    fn handle_event(&mut self, event: &Event) -> Option<AppAction>;
+
+    fn render(&mut self, frame: &mut Frame<'_>, area: Rect);
+}
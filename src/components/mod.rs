@@ -0,0 +1,3 @@
+pub mod result_list;
+pub mod search_bar;
+pub mod status_bar;
@@ -0,0 +1,101 @@
+use crate::action::AppAction;
+use crate::component::Component;
+use crate::search::SearchResult;
+use crate::stateful_list::StatefulList;
+use crossterm::event::{Event, KeyCode};
+use ratatui::{
+    prelude::*,
+    widgets::{block::*, *},
+};
+
+/// The scrollable list of search results, and the single source of truth
+/// for which one (if any) is currently selected.
+#[derive(Debug, Default)]
+pub struct ResultList {
+    all_items: Vec<SearchResult>,
+    items: StatefulList<SearchResult>,
+}
+
+impl ResultList {
+    /// Replaces both the full result set and the displayed list after a
+    /// search completes.
+    pub fn set_items(&mut self, items: Vec<SearchResult>) {
+        self.all_items = items.clone();
+        self.items = StatefulList::with_items(items);
+    }
+
+    /// Recomputes the displayed list from the full result set using a
+    /// case-insensitive match against the title.
+    pub fn apply_filter(&mut self, query: &str) {
+        let query = query.to_lowercase();
+        let filtered: Vec<SearchResult> = self
+            .all_items
+            .iter()
+            .filter(|item| item.title.to_lowercase().contains(&query))
+            .cloned()
+            .collect();
+
+        self.items = StatefulList::with_items(filtered);
+        if !self.items.items.is_empty() {
+            self.items.state.select(Some(0));
+        }
+    }
+
+    /// Drops the filter and shows the full result set again.
+    pub fn reset_filter(&mut self) {
+        self.items = StatefulList::with_items(self.all_items.clone());
+    }
+
+    pub fn selected(&self) -> Option<&SearchResult> {
+        self.items.selected()
+    }
+}
+
+impl Component for ResultList {
+    fn handle_event(&mut self, event: &Event) -> Option<AppAction> {
+        let Event::Key(key_event) = event else {
+            return None;
+        };
+
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.items.next();
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.items.previous();
+                None
+            }
+            KeyCode::Char('h') => {
+                self.items.unselect();
+                None
+            }
+            KeyCode::Enter | KeyCode::Char('o') => Some(AppAction::Play),
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame<'_>, area: Rect) {
+        let rows: Vec<String> = self
+            .items
+            .items
+            .iter()
+            .map(|item| item.to_string())
+            .collect();
+        let list = List::new(rows)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Youtube videos ")
+                    .title_alignment(Alignment::Center),
+            )
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::Yellow),
+            )
+            .highlight_symbol("> ");
+
+        frame.render_stateful_widget(list, area, &mut self.items.state);
+    }
+}
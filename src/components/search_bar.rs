@@ -0,0 +1,105 @@
+use crate::action::AppAction;
+use crate::component::Component;
+use crossterm::event::{Event, KeyCode};
+use ratatui::{
+    prelude::*,
+    widgets::{block::*, *},
+};
+use tui_input::{backend::crossterm::EventHandler, Input};
+
+/// Which of the search bar's two jobs it's currently doing, or `None` if
+/// neither has focus and it's just showing the last query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Search,
+    Filter,
+}
+
+/// The top input box. Doubles as a search query box and, while filtering,
+/// a live filter box over the already-fetched results; `focused_mode`
+/// decides which input is live and how the box renders.
+#[derive(Debug, Default)]
+pub struct SearchBar {
+    focused_mode: Option<Mode>,
+    query_input: Input,
+    filter_input: Input,
+}
+
+impl SearchBar {
+    pub fn set_focus(&mut self, mode: Option<Mode>) {
+        self.focused_mode = mode;
+    }
+
+    pub fn reset_filter(&mut self) {
+        self.filter_input = Input::default();
+    }
+}
+
+impl Component for SearchBar {
+    fn handle_event(&mut self, event: &Event) -> Option<AppAction> {
+        let mode = self.focused_mode?;
+        let Event::Key(key_event) = event else {
+            return None;
+        };
+
+        match mode {
+            Mode::Search => match key_event.code {
+                KeyCode::Enter => Some(AppAction::Search(self.query_input.value().to_string())),
+                KeyCode::Esc => Some(AppAction::FocusNormal),
+                _ => {
+                    self.query_input.handle_event(event);
+                    None
+                }
+            },
+            Mode::Filter => match key_event.code {
+                KeyCode::Enter => Some(AppAction::FocusNormal),
+                KeyCode::Esc => {
+                    self.reset_filter();
+                    Some(AppAction::ResetFilter)
+                }
+                _ => {
+                    self.filter_input.handle_event(event);
+                    Some(AppAction::Filter(self.filter_input.value().to_string()))
+                }
+            },
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame<'_>, area: Rect) {
+        let active_input = match self.focused_mode {
+            Some(Mode::Filter) => &self.filter_input,
+            _ => &self.query_input,
+        };
+        let title = match self.focused_mode {
+            Some(Mode::Filter) => " Filter ",
+            _ => " Search ",
+        };
+
+        let width = area.width.max(3) - 3 - 2; // keep 2 for borders and 1 for cursor
+        let scroll = active_input.visual_scroll(width as usize);
+
+        let input = Paragraph::new(active_input.value())
+            .style(match self.focused_mode {
+                None => Style::default(),
+                Some(_) => Style::default().fg(Color::Yellow),
+            })
+            .scroll((0, scroll as u16))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .padding(Padding::horizontal(1)),
+            );
+
+        frame.render_widget(input, area);
+
+        if self.focused_mode.is_some() {
+            // Make the cursor visible and ask ratatui to put it at the
+            // specified coordinates after rendering.
+            frame.set_cursor(
+                area.x + ((active_input.visual_cursor()).max(scroll) - scroll) as u16 + 1 + 1,
+                area.y + 1,
+            );
+        }
+    }
+}
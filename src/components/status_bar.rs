@@ -0,0 +1,29 @@
+use crate::action::AppAction;
+use crate::component::Component;
+use crossterm::event::Event;
+use ratatui::{prelude::*, widgets::block::*};
+
+const DEFAULT_HELP: &str =
+    "h - unselect, j - move down, k - move up, o/enter - play, s - search, / - filter, ? - help, esc - exit mode";
+
+/// Bottom help line, replaced by a status message (e.g. "Playing: ...")
+/// once one is set. Nothing clears the status again; a later message only
+/// replaces it.
+#[derive(Debug, Default)]
+pub struct StatusBar {
+    pub status: Option<String>,
+}
+
+impl Component for StatusBar {
+    fn handle_event(&mut self, _event: &Event) -> Option<AppAction> {
+        None
+    }
+
+    fn render(&mut self, frame: &mut Frame<'_>, area: Rect) {
+        let text = self.status.as_deref().unwrap_or(DEFAULT_HELP);
+        frame.render_widget(
+            Block::default().title(Title::from(text).alignment(Alignment::Center)),
+            area,
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use ratatui::prelude::*;
+
+/// A single key binding shown in the `?` help popup.
+///
+/// The popup renders straight from this registry. `App::handle_key_event`
+/// matches key codes itself and does not read this registry, so the two
+/// are kept in step by hand.
+pub struct KeyCommand {
+    pub keys: &'static str,
+    pub description: &'static str,
+}
+
+pub const NORMAL_MODE_COMMANDS: &[KeyCommand] = &[
+    KeyCommand {
+        keys: "j / Down",
+        description: "select next result",
+    },
+    KeyCommand {
+        keys: "k / Up",
+        description: "select previous result",
+    },
+    KeyCommand {
+        keys: "h",
+        description: "clear selection",
+    },
+    KeyCommand {
+        keys: "o / Enter",
+        description: "play selected video",
+    },
+    KeyCommand {
+        keys: "s",
+        description: "search",
+    },
+    KeyCommand {
+        keys: "/",
+        description: "filter results",
+    },
+    KeyCommand {
+        keys: "?",
+        description: "toggle this help",
+    },
+    KeyCommand {
+        keys: "q / Esc",
+        description: "quit",
+    },
+];
+
+/// A rect centered within `area`, `percent_x`/`percent_y` of its size.
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::vertical([
+        Constraint::Percentage((100 - percent_y) / 2),
+        Constraint::Percentage(percent_y),
+        Constraint::Percentage((100 - percent_y) / 2),
+    ])
+    .split(area);
+
+    Layout::horizontal([
+        Constraint::Percentage((100 - percent_x) / 2),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage((100 - percent_x) / 2),
+    ])
+    .split(vertical[1])[1]
+}
@@ -3,40 +3,76 @@ use ratatui::{
     prelude::*,
     widgets::{block::*, *},
 };
-use tui_input::{backend::crossterm::EventHandler, Input};
+mod action;
+mod component;
+mod components;
+mod help;
+mod playback;
+mod search;
+mod stateful_list;
 mod tui;
-use std::{io, time::Duration, vec};
+use action::AppAction;
+use component::Component;
+use components::{result_list::ResultList, search_bar, search_bar::SearchBar, status_bar::StatusBar};
+use search::SearchResult;
+use std::{
+    io,
+    process::Child,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::Duration,
+};
+
+/// Which component currently owns raw key input; `None` means the result
+/// list has focus (the default, "normal" state).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Search,
+    Filter,
+}
 
 #[derive(Debug)]
 pub struct App {
-    input_mode: InputMode,
+    focus: Option<Focus>,
     exit: bool,
-    input: Input,
-    search_items: Vec<String>,
-    selected_item: String,
+    search_bar: SearchBar,
+    result_list: ResultList,
+    status_bar: StatusBar,
+    search_rx: Option<Receiver<io::Result<Vec<SearchResult>>>>,
+    /// Command used to spawn a player, read from `YTUI_PLAYER` at startup;
+    /// override when mpv isn't the right fit for a given setup.
+    player_cmd: String,
+    /// Still-running players, reaped opportunistically so they never
+    /// accumulate as zombies.
+    players: Vec<Child>,
+    /// Whether the `?` keyboard-shortcut popup is currently shown.
+    help_visible: bool,
 }
 
 impl Default for App {
     fn default() -> Self {
         Self {
-            input_mode: InputMode::Normal,
-            exit: Default::default(),
-            input: Default::default(),
-            search_items: Default::default(),
-            selected_item: Default::default(),
+            focus: None,
+            exit: false,
+            search_bar: SearchBar::default(),
+            result_list: ResultList::default(),
+            status_bar: StatusBar::default(),
+            search_rx: None,
+            player_cmd: std::env::var("YTUI_PLAYER")
+                .unwrap_or_else(|_| playback::DEFAULT_PLAYER.to_string()),
+            players: Vec::new(),
+            help_visible: false,
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum InputMode {
-    Editing,
-    Normal,
-}
-
 impl App {
     /// runs the application's main loop until the user quits
     pub fn run(&mut self, terminal: &mut tui::Tui) -> io::Result<()> {
+        self.search_bar.init()?;
+        self.result_list.init()?;
+        self.status_bar.init()?;
+
         while !self.exit {
             terminal.draw(|frame| self.render_frame(frame))?;
             self.handle_events()?;
@@ -46,42 +82,148 @@ impl App {
 
     /// updates the application's state based on user input
     fn handle_events(&mut self) -> io::Result<()> {
-        match event::read()? {
-            // it's important to check that the event is a key press event as
-            // crossterm also emits key release and repeat events on Windows.
-            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                self.handle_key_event(key_event)
+        // Poll rather than block so a pending search on `search_rx` still
+        // gets picked up while the user isn't touching the keyboard.
+        if event::poll(Duration::from_millis(100))? {
+            match event::read()? {
+                // it's important to check that the event is a key press event as
+                // crossterm also emits key release and repeat events on Windows.
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                    self.handle_key_event(key_event)
+                }
+                _ => {}
             }
-            _ => {}
         }
 
+        self.poll_search();
+        self.reap_players();
+
         Ok(())
     }
 
+    /// Routes a key event to the focused component (or handles the global
+    /// bindings that change focus) and applies whatever action comes back.
     fn handle_key_event(&mut self, key_event: KeyEvent) {
-        match self.input_mode {
-            InputMode::Editing => match key_event.code {
-                KeyCode::Enter => self.input_mode = InputMode::Normal,
-                KeyCode::Esc => {
-                    self.input_mode = InputMode::Normal;
-                }
-                _ => {
-                    self.input.handle_event(&Event::Key(key_event));
+        if self.help_visible {
+            if matches!(key_event.code, KeyCode::Esc | KeyCode::Char('?')) {
+                self.help_visible = false;
+            }
+            return;
+        }
+
+        let event = Event::Key(key_event);
+
+        let action = if self.focus.is_some() {
+            self.search_bar.handle_event(&event)
+        } else {
+            match key_event.code {
+                KeyCode::Char('q') | KeyCode::Esc => Some(AppAction::Quit),
+                KeyCode::Char('s') => Some(AppAction::FocusSearch),
+                KeyCode::Char('/') => Some(AppAction::FocusFilter),
+                KeyCode::Char('?') => {
+                    self.help_visible = true;
+                    None
                 }
-            },
-            InputMode::Normal => match key_event.code {
-                KeyCode::Char('q') | KeyCode::Esc => self.exit(),
-                KeyCode::Char('s') => self.input_mode = InputMode::Editing,
-                _ => {}
-            },
+                _ => self.result_list.handle_event(&event),
+            }
+        };
+
+        if let Some(action) = action {
+            self.apply_action(action);
         }
     }
 
-    fn exit(&mut self) {
-        self.exit = true;
+    /// Applies an action gathered from a component, the single place state
+    /// that spans more than one component gets mutated.
+    fn apply_action(&mut self, action: AppAction) {
+        match action {
+            AppAction::Quit => self.exit = true,
+            AppAction::Search(query) => {
+                self.focus = None;
+                self.search_bar.set_focus(None);
+                self.search_bar.reset_filter();
+                self.start_search(query);
+            }
+            AppAction::Filter(query) => self.result_list.apply_filter(&query),
+            AppAction::ResetFilter => {
+                self.focus = None;
+                self.search_bar.set_focus(None);
+                self.result_list.reset_filter();
+            }
+            AppAction::Play => self.play_selected(),
+            AppAction::FocusSearch => {
+                self.focus = Some(Focus::Search);
+                self.search_bar.set_focus(Some(search_bar::Mode::Search));
+            }
+            AppAction::FocusFilter => {
+                self.focus = Some(Focus::Filter);
+                self.search_bar.set_focus(Some(search_bar::Mode::Filter));
+            }
+            AppAction::FocusNormal => {
+                self.focus = None;
+                self.search_bar.set_focus(None);
+            }
+        }
     }
 
-    fn render_frame(&self, frame: &mut Frame<'_>) {
+    /// Reaps any players that have since exited so they don't accumulate
+    /// as zombie processes.
+    fn reap_players(&mut self) {
+        self.players.retain_mut(|child| !matches!(child.try_wait(), Ok(Some(_))));
+    }
+
+    /// Checks the worker thread spawned by a search for a finished result
+    /// without blocking the render loop.
+    fn poll_search(&mut self) {
+        let Some(rx) = &self.search_rx else {
+            return;
+        };
+
+        match rx.try_recv() {
+            Ok(Ok(results)) => {
+                self.result_list.set_items(results);
+                self.search_rx = None;
+            }
+            Ok(Err(err)) => {
+                self.result_list.set_items(Vec::new());
+                self.status_bar.status = Some(format!("Search failed: {err}"));
+                self.search_rx = None;
+            }
+            Err(mpsc::TryRecvError::Empty) => {}
+            Err(mpsc::TryRecvError::Disconnected) => self.search_rx = None,
+        }
+    }
+
+    /// Kicks off a search on a worker thread and stashes the receiving end
+    /// of the channel so `poll_search` can pick up the result later.
+    fn start_search(&mut self, query: String) {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = tx.send(search::search(&query));
+        });
+        self.search_rx = Some(rx);
+    }
+
+    /// Spawns a player for the currently selected video, if any, leaving
+    /// the TUI running and the player backgrounded.
+    fn play_selected(&mut self) {
+        let Some(item) = self.result_list.selected() else {
+            return;
+        };
+
+        match playback::play(&item.url(), &self.player_cmd) {
+            Ok(child) => {
+                self.status_bar.status = Some(format!("Playing: {}", item.title));
+                self.players.push(child);
+            }
+            Err(err) => {
+                self.status_bar.status =
+                    Some(format!("Failed to start {}: {err}", self.player_cmd));
+            }
+        }
+    }
+
+    fn render_frame(&mut self, frame: &mut Frame<'_>) {
         let vertical = Layout::vertical([
             Constraint::Length(1),
             Constraint::Length(3),
@@ -97,101 +239,41 @@ impl App {
 
         frame.render_widget(title_block, title);
 
-        let width = input_box.width.max(3) - 3 - 2; // keep 2 for borders and 1 for cursor
-
-        let scroll = self.input.visual_scroll(width as usize);
-
-        let input = Paragraph::new(self.input.value())
-            .style(match self.input_mode {
-                InputMode::Normal => Style::default(),
-                InputMode::Editing => Style::default().fg(Color::Yellow),
-            })
-            .scroll((0, scroll as u16))
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Search ")
-                    .padding(Padding::horizontal(1)),
-            );
-
-        frame.render_widget(input, input_box);
-
-        match self.input_mode {
-            InputMode::Normal =>
-                // Hide the cursor. `Frame` does this by default, so we don't need to do anything here
-                {}
-
-            InputMode::Editing => {
-                // Make the cursor visible and ask tui-rs to put it at the specified coordinates after rendering
-                frame.set_cursor(
-                    // Put cursor past the end of the input text
-                    input_box.x
-                        + ((self.input.visual_cursor()).max(scroll) - scroll) as u16
-                        + 1
-                        + 1,
-                    // Move one line down, from the border to the input line
-                    input_box.y + 1,
-                )
-            }
+        self.search_bar.render(frame, input_box);
+        self.result_list.render(frame, content);
+        self.status_bar.render(frame, help);
+
+        if self.help_visible {
+            self.render_help_popup(frame);
         }
+    }
 
-        // let (msg, style) = match self.input_mode {
-        //     InputMode::Normal => (
-        //         vec![
-        //             Span::raw("Press "),
-        //             Span::styled("q", Style::default().add_modifier(Modifier::BOLD)),
-        //             Span::raw(" to exit, "),
-        //             Span::styled("e", Style::default().add_modifier(Modifier::BOLD)),
-        //             Span::raw(" to start editing."),
-        //         ],
-        //         Style::default().add_modifier(Modifier::RAPID_BLINK),
-        //     ),
-        //     InputMode::Editing => (
-        //         vec![
-        //             Span::raw("Press "),
-        //             Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
-        //             Span::raw(" to stop editing, "),
-        //             Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-        //             Span::raw(" to record the message"),
-        //         ],
-        //         Style::default(),
-        //     ),
-        // };
-        // let text = Text::from(Line::from(msg)).patch_style(style);
-        // let help_message = Paragraph::new(text);
-        // frame.render_widget(help_message, search_box);
-
-        // frame.render_widget(paragraph, search_box);
-
-        frame.render_widget(
-            Block::default().title(
-                Title::from("h - move left, j - move down, k - move up, l - move right , s - enter search mode, esc - exit search mode")
-                    .alignment(Alignment::Center),
-            ),
-            help,
-        );
+    /// Renders the `?` keyboard-shortcut popup from `help::NORMAL_MODE_COMMANDS`.
+    /// `handle_key_event` does not read that registry; its bindings are
+    /// matched separately.
+    fn render_help_popup(&self, frame: &mut Frame<'_>) {
+        let popup_area = help::centered_rect(50, 50, frame.size());
+        frame.render_widget(Clear, popup_area);
 
-        let list = List::new(self.search_items.to_owned()).block(
+        let rows: Vec<ListItem> = help::NORMAL_MODE_COMMANDS
+            .iter()
+            .map(|cmd| ListItem::new(format!("{:<10} {}", cmd.keys, cmd.description)))
+            .collect();
+
+        let list = List::new(rows).block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Youtube videos ")
+                .title(" Keyboard shortcuts ")
                 .title_alignment(Alignment::Center),
         );
 
-        frame.render_widget(list, content);
+        frame.render_widget(list, popup_area);
     }
 }
 
 fn main() -> io::Result<()> {
     let mut terminal = tui::init()?;
-    let app_result = App {
-        exit: false,
-        input_mode: InputMode::Normal,
-        search_items: vec![],
-        selected_item: "".to_string(),
-        input: Input::default(),
-    }
-    .run(&mut terminal);
+    let app_result = App::default().run(&mut terminal);
     tui::restore()?;
     terminal.show_cursor()?;
     app_result
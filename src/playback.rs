@@ -0,0 +1,22 @@
+use std::io;
+use std::process::{Child, Command, Stdio};
+
+/// Command used to play a resolved video URL.
+///
+/// Defaults to `mpv`; swap this for another player (or a wrapper script)
+/// on setups where mpv isn't the right fit.
+pub const DEFAULT_PLAYER: &str = "mpv";
+
+/// Spawns an external player against `url` without blocking the caller.
+///
+/// The child's stdio is detached from the terminal so the player's own
+/// output doesn't clobber the TUI's rendered frame, and the process keeps
+/// running in the background once spawned.
+pub fn play(url: &str, player: &str) -> io::Result<Child> {
+    Command::new(player)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+}
@@ -0,0 +1,62 @@
+use std::io;
+use std::process::Command;
+
+/// A single video returned by a search query.
+///
+/// Carries enough metadata to render a result row (`title`) while keeping
+/// the video id around so later actions (playback, details) don't need to
+/// re-resolve it from the title.
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub title: String,
+    pub video_id: String,
+}
+
+impl std::fmt::Display for SearchResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.title)
+    }
+}
+
+impl SearchResult {
+    /// The watch URL for this video, suitable for handing to a player.
+    pub fn url(&self) -> String {
+        format!("https://www.youtube.com/watch?v={}", self.video_id)
+    }
+}
+
+/// Runs a YouTube search and returns the matching videos.
+///
+/// Shells out to `yt-dlp --flat-playlist --dump-json` against a
+/// `ytsearch<n>:` pseudo URL so we avoid depending on an API key. This
+/// blocks on the child process, so callers that care about a responsive
+/// render loop should run it on a worker thread rather than calling it
+/// directly from the event loop.
+pub fn search(query: &str) -> io::Result<Vec<SearchResult>> {
+    let output = Command::new("yt-dlp")
+        .args([
+            "--flat-playlist",
+            "--dump-json",
+            &format!("ytsearch20:{query}"),
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let results = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|entry| {
+            let title = entry.get("title")?.as_str()?.to_string();
+            let video_id = entry.get("id")?.as_str()?.to_string();
+            Some(SearchResult { title, video_id })
+        })
+        .collect();
+
+    Ok(results)
+}